@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Float;
+
+/// A bound on a single parameter.
+///
+/// Unbounded algorithms are confined to a box by the parameter transformation
+/// documented at the [crate] root, converting between the bounded "external"
+/// values the user sees and the unbounded "internal" values the algorithm
+/// searches over. A parameter may also be [`Bound::Fixed`] to a constant value, in
+/// which case it is held out of the optimization entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Bound {
+    /// No bound; the parameter is free over all of $`\mathbb{R}`$.
+    NoBound,
+    /// A lower bound only.
+    LowerBound(Float),
+    /// An upper bound only.
+    UpperBound(Float),
+    /// Both a lower and an upper bound.
+    LowerAndUpperBound(Float, Float),
+    /// The parameter is frozen at the given constant and not varied.
+    Fixed(Float),
+}
+
+impl Bound {
+    /// The lower edge of the bound, or $`-\infty`$ if there is none. A
+    /// [`Bound::Fixed`] parameter reports its constant value.
+    #[must_use]
+    pub const fn lower(&self) -> Float {
+        match *self {
+            Self::LowerBound(lo) | Self::LowerAndUpperBound(lo, _) | Self::Fixed(lo) => lo,
+            Self::NoBound | Self::UpperBound(_) => Float::NEG_INFINITY,
+        }
+    }
+
+    /// The upper edge of the bound, or $`+\infty`$ if there is none. A
+    /// [`Bound::Fixed`] parameter reports its constant value.
+    #[must_use]
+    pub const fn upper(&self) -> Float {
+        match *self {
+            Self::UpperBound(hi) | Self::LowerAndUpperBound(_, hi) | Self::Fixed(hi) => hi,
+            Self::NoBound | Self::LowerBound(_) => Float::INFINITY,
+        }
+    }
+
+    /// Whether the parameter is frozen at a constant value.
+    #[must_use]
+    pub const fn is_fixed(&self) -> bool {
+        matches!(self, Self::Fixed(_))
+    }
+
+    /// Whether `value` sits at (or extremely close to) one of the finite bound
+    /// edges. Fixed parameters never report as "at bound"; use [`Bound::is_fixed`].
+    #[must_use]
+    pub fn at_bound(&self, value: Float) -> bool {
+        match *self {
+            Self::NoBound | Self::Fixed(_) => false,
+            Self::LowerBound(lo) => (value - lo).abs() < Float::EPSILON,
+            Self::UpperBound(hi) => (value - hi).abs() < Float::EPSILON,
+            Self::LowerAndUpperBound(lo, hi) => {
+                (value - lo).abs() < Float::EPSILON || (value - hi).abs() < Float::EPSILON
+            }
+        }
+    }
+
+    /// Convert an external (bounded) parameter into its internal (unbounded)
+    /// representation.
+    #[must_use]
+    pub fn to_unbounded(&self, ext: Float) -> Float {
+        match *self {
+            Self::NoBound | Self::Fixed(_) => ext,
+            Self::LowerBound(lo) => ((ext - lo + 1.0).powi(2) - 1.0).sqrt(),
+            Self::UpperBound(hi) => ((hi - ext + 1.0).powi(2) - 1.0).sqrt(),
+            Self::LowerAndUpperBound(lo, hi) => {
+                (2.0 * (ext - lo) / (hi - lo) - 1.0).asin()
+            }
+        }
+    }
+
+    /// Convert an internal (unbounded) parameter back into its external (bounded)
+    /// representation.
+    #[must_use]
+    pub fn to_bounded(&self, int: Float) -> Float {
+        match *self {
+            Self::NoBound => int,
+            Self::Fixed(value) => value,
+            Self::LowerBound(lo) => lo - 1.0 + (int * int + 1.0).sqrt(),
+            Self::UpperBound(hi) => hi + 1.0 - (int * int + 1.0).sqrt(),
+            Self::LowerAndUpperBound(lo, hi) => lo + (int.sin() + 1.0) * (hi - lo) / 2.0,
+        }
+    }
+}
+
+/// A collection of per-parameter [`Bound`]s.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bounds(Vec<Bound>);
+
+impl Bounds {
+    /// Construct a set of bounds from a vector of [`Bound`]s.
+    #[must_use]
+    pub const fn new(bounds: Vec<Bound>) -> Self {
+        Self(bounds)
+    }
+
+    /// Consume the bounds, returning the underlying vector.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<Bound> {
+        self.0
+    }
+
+    /// A view of the underlying bounds.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Bound] {
+        &self.0
+    }
+
+    /// The number of parameters these bounds describe.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no bounds.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The indices of the free (non-fixed) parameters, in order.
+    #[must_use]
+    pub fn free_indices(&self) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| !b.is_fixed())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The number of free (non-fixed) parameters.
+    #[must_use]
+    pub fn n_free(&self) -> usize {
+        self.0.iter().filter(|b| !b.is_fixed()).count()
+    }
+
+    /// Splice a reduced vector of *internal* free-parameter values back into the
+    /// full *external* parameter vector: each free slot is mapped through its box
+    /// transform with [`Bound::to_bounded`], and each [`Bound::Fixed`] slot takes
+    /// its constant. This is the inverse of [`Bounds::to_free`].
+    #[must_use]
+    pub fn to_full(&self, free: &[Float]) -> Vec<Float> {
+        let mut it = free.iter().copied();
+        self.0
+            .iter()
+            .map(|b| match *b {
+                Bound::Fixed(value) => value,
+                b => b.to_bounded(it.next().unwrap_or(Float::NAN)),
+            })
+            .collect()
+    }
+
+    /// Project a full *external* parameter vector down to its *internal* free
+    /// components, mapping each through [`Bound::to_unbounded`] so the box transform
+    /// is applied. This is the inverse of [`Bounds::to_full`].
+    #[must_use]
+    pub fn to_free(&self, full: &[Float]) -> Vec<Float> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| !b.is_fixed())
+            .map(|(i, b)| b.to_unbounded(full[i]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_splicing() {
+        // Unbounded parameters pass through the box transform unchanged, so the
+        // fixed-value splicing is visible directly.
+        let bounds = Bounds::new(vec![Bound::NoBound, Bound::Fixed(3.0), Bound::NoBound]);
+        assert_eq!(bounds.n_free(), 2);
+        assert_eq!(bounds.free_indices(), vec![0, 2]);
+        let full = bounds.to_full(&[1.0, 2.0]);
+        assert_eq!(full, vec![1.0, 3.0, 2.0]);
+        assert_eq!(bounds.to_free(&full), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_box_transform_applied() {
+        // A box-bounded free parameter is mapped into its range by `to_full`, and
+        // `to_free` recovers the internal value it came from.
+        let bounds = Bounds::new(vec![Bound::LowerAndUpperBound(-1.0, 1.0)]);
+        let full = bounds.to_full(&[0.5]);
+        assert!(full[0] > -1.0 && full[0] < 1.0);
+        assert!((bounds.to_free(&full)[0] - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bounded_roundtrip() {
+        let b = Bound::LowerAndUpperBound(-1.0, 1.0);
+        let ext = 0.3;
+        assert!((b.to_bounded(b.to_unbounded(ext)) - ext).abs() < 1e-12);
+    }
+}
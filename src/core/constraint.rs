@@ -0,0 +1,78 @@
+//! General equality and inequality constraints, beyond the box bounds expressed
+//! by [`Bound`](crate::core::Bound).
+
+use nalgebra::DVector;
+
+use crate::traits::CostFunction;
+use crate::Float;
+
+/// Whether a [`Constraint`] is an equality $`g(x) = 0`$ or an inequality
+/// $`g(x) \le 0`$.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// $`g(x) = 0`$.
+    Equality,
+    /// $`g(x) \le 0`$.
+    Inequality,
+}
+
+/// A general nonlinear constraint $`g(x)`$ of a given [`ConstraintKind`].
+///
+/// The constraint function is itself a scalar [`CostFunction`], so its gradient is
+/// available analytically or via the same central finite difference used
+/// everywhere else. Constraints are attached to a run through `Minimizer::setup`
+/// and handled by the [`SQP`](crate::solvers::constrained::SQP) solver.
+pub struct Constraint<U, E> {
+    /// The constraint function $`g(x)`$.
+    pub function: Box<dyn CostFunction<U, E>>,
+    /// Whether the constraint is an equality or an inequality.
+    pub kind: ConstraintKind,
+}
+
+impl<U, E> Constraint<U, E> {
+    /// Build an equality constraint $`g(x) = 0`$.
+    #[must_use]
+    pub fn equality(function: Box<dyn CostFunction<U, E>>) -> Self {
+        Self {
+            function,
+            kind: ConstraintKind::Equality,
+        }
+    }
+
+    /// Build an inequality constraint $`g(x) \le 0`$.
+    #[must_use]
+    pub fn inequality(function: Box<dyn CostFunction<U, E>>) -> Self {
+        Self {
+            function,
+            kind: ConstraintKind::Inequality,
+        }
+    }
+
+    /// Evaluate the constraint residual $`g(x)`$.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if the constraint function fails to evaluate at `x`.
+    pub fn residual(&self, x: &[Float], user_data: &mut U) -> Result<Float, E> {
+        self.function.evaluate(x, user_data)
+    }
+
+    /// Evaluate the constraint gradient $`\nabla g(x)`$.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if the constraint gradient fails to evaluate at `x`.
+    pub fn gradient(&self, x: &[Float], user_data: &mut U) -> Result<DVector<Float>, E> {
+        self.function.gradient(x, user_data)
+    }
+
+    /// The non-negative violation of the constraint: $`|g(x)|`$ for an equality and
+    /// $`\max(0, g(x))`$ for an inequality.
+    #[must_use]
+    pub fn violation(&self, residual: Float) -> Float {
+        match self.kind {
+            ConstraintKind::Equality => residual.abs(),
+            ConstraintKind::Inequality => residual.max(0.0),
+        }
+    }
+}
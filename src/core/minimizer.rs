@@ -0,0 +1,430 @@
+//! The [`Minimizer`] drives a [`Solver`] to completion, handling the bits common
+//! to every algorithm: the starting point, the iteration budget, an abort signal,
+//! and the projection of fixed parameters out of the search space.
+
+use std::path::Path;
+
+use nalgebra::DVector;
+
+use crate::core::{Bound, Bounds, Checkpoint, Constraint, Summary};
+use crate::solvers::constrained::SQP;
+use crate::traits::{AbortSignal, CostFunction, Observer, Solver, Terminator};
+use crate::Float;
+
+/// Wraps a user [`CostFunction`] so the solver only ever sees the free (non-fixed)
+/// parameters in their internal, unbounded representation: each evaluation maps the
+/// free values back through the box transform, splices the fixed values into their
+/// slots, and calls the underlying function with the resulting external vector.
+struct Projected<'a, P> {
+    inner: &'a P,
+    bounds: &'a Bounds,
+}
+
+impl<U, E, P> CostFunction<U, E> for Projected<'_, P>
+where
+    P: CostFunction<U, E>,
+{
+    fn evaluate(&self, free: &[Float], user_data: &mut U) -> Result<Float, E> {
+        self.inner.evaluate(&self.bounds.to_full(free), user_data)
+    }
+}
+
+/// A synthetic equality constraint $`x_i - c = 0`$ used to hold a [`Bound::Fixed`]
+/// parameter constant on the constrained [`SQP`] path, where the free-subspace
+/// projection of the unconstrained path does not apply.
+struct CoordinateOffset {
+    index: usize,
+    value: Float,
+}
+
+impl<U, E> CostFunction<U, E> for CoordinateOffset {
+    fn evaluate(&self, x: &[Float], _user_data: &mut U) -> Result<Float, E> {
+        Ok(x[self.index] - self.value)
+    }
+    fn gradient(&self, x: &[Float], _user_data: &mut U) -> Result<DVector<Float>, E> {
+        let mut g = DVector::zeros(x.len());
+        g[self.index] = 1.0;
+        Ok(g)
+    }
+}
+
+/// Drives a boxed [`Solver`] to minimize a [`CostFunction`].
+///
+/// The minimizer owns the run's [`Summary`] (exposed as [`Minimizer::status`]), the
+/// iteration budget, an optional abort signal, and the parameter [`Bounds`]. When
+/// any parameter is [`Bound::Fixed`], the minimizer projects the search into the
+/// reduced free subspace — the solver never sees the fixed coordinates, they are
+/// spliced back to their constants before each evaluation, they are excluded from
+/// the finite-difference gradient, and they are reported with zero uncertainty.
+pub struct Minimizer<U, E> {
+    solver: Box<dyn Solver<U, E>>,
+    /// The current status of the minimization.
+    pub status: Summary,
+    max_steps: usize,
+    bounds: Option<Bounds>,
+    abort_signal: Option<Box<dyn AbortSignal>>,
+    terminator: Option<Box<dyn Terminator>>,
+    observers: Vec<Box<dyn Observer>>,
+    constraints: Vec<Constraint<U, E>>,
+}
+
+impl<U, E> Minimizer<U, E> {
+    /// Create a minimizer driving the given solver.
+    #[must_use]
+    pub fn new(solver: impl Solver<U, E> + 'static) -> Self {
+        Self {
+            solver: Box::new(solver),
+            status: Summary::default(),
+            max_steps: 10_000,
+            bounds: None,
+            abort_signal: None,
+            terminator: None,
+            observers: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Seed the run from a checkpoint written earlier by a
+    /// [`Checkpoint`](crate::core::Checkpoint) observer, resuming from the last
+    /// saved point rather than a fresh start.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`std::io::Error`] if the checkpoint cannot be read or does not
+    /// contain a valid serialized [`Summary`].
+    pub fn resume_from(mut self, path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let status = Checkpoint::load(path)?;
+        // Restart the solver from the point the checkpoint left off at.
+        let resume = status.x.clone();
+        self.status = status.with_x0(resume);
+        Ok(self)
+    }
+
+    /// Apply a builder closure, returning the configured minimizer. This is the
+    /// idiomatic way to configure a minimizer before a run.
+    #[must_use]
+    pub fn setup(self, f: impl FnOnce(Self) -> Self) -> Self {
+        f(self)
+    }
+
+    /// Set the maximum number of iterations.
+    #[must_use]
+    pub const fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Attach an abort signal polled between iterations.
+    #[must_use]
+    pub fn with_abort_signal(mut self, signal: impl AbortSignal + 'static) -> Self {
+        self.abort_signal = Some(Box::new(signal));
+        self
+    }
+
+    /// Set the parameter bounds, including any [`Bound::Fixed`] parameters.
+    #[must_use]
+    pub fn with_bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Attach a convergence criterion, consulted after every iteration on top of
+    /// the solver's own internal convergence test. Pass a composite built from the
+    /// [`And`](crate::core::And), [`Or`](crate::core::Or) and [`Not`](crate::core::Not)
+    /// combinators to express several criteria at once.
+    #[must_use]
+    pub fn with_terminator(mut self, terminator: impl Terminator + 'static) -> Self {
+        self.terminator = Some(Box::new(terminator));
+        self
+    }
+
+    /// Register an observer, called with the running status after every iteration.
+    /// Observers are invoked in registration order.
+    #[must_use]
+    pub fn with_observer(mut self, observer: impl Observer + 'static) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    /// Attach a general equality/inequality [`Constraint`]. When any constraint is
+    /// attached the run is handed to the [`SQP`] solver instead of the boxed
+    /// [`Solver`], which forms the constrained KKT step directly; the residuals and
+    /// Lagrange multipliers are reported in [`Minimizer::status`].
+    ///
+    /// Any [`Bound::Fixed`] parameters are carried onto this path as equality
+    /// constraints, so they are still held constant. Box bounds and the per-iteration
+    /// observer/terminator/abort hooks, however, apply only to the unconstrained
+    /// path — express box bounds as inequality constraints when using the SQP path.
+    #[must_use]
+    pub fn with_constraint(mut self, constraint: Constraint<U, E>) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Attach several general [`Constraint`]s at once. See [`Minimizer::with_constraint`].
+    #[must_use]
+    pub fn with_constraints(mut self, constraints: Vec<Constraint<U, E>>) -> Self {
+        self.constraints.extend(constraints);
+        self
+    }
+
+    /// Edit the initial status, typically to set the starting point with
+    /// [`Summary::with_x0`].
+    #[must_use]
+    pub fn on_status(mut self, f: impl FnOnce(Summary) -> Summary) -> Self {
+        self.status = f(self.status);
+        self
+    }
+
+    /// Resolve the bounds for a run of dimension `n`, defaulting to unbounded.
+    fn resolved_bounds(&self, n: usize) -> Bounds {
+        self.bounds
+            .clone()
+            .unwrap_or_else(|| Bounds::new(vec![Bound::NoBound; n]))
+    }
+
+    /// Copy the solver's (free-space) status into the full-space run status.
+    fn sync_status(&mut self, bounds: &Bounds) {
+        let solver_status = self.solver.status();
+        let free_vals: Vec<Float> = solver_status.x.iter().map(|&v| v as Float).collect();
+        self.status.x = bounds.to_full(&free_vals).iter().map(|&v| v as f64).collect();
+        let free = bounds.free_indices();
+        self.status.fx = solver_status.fx;
+        self.status.cost_evals = solver_status.cost_evals;
+        self.status.gradient_evals = solver_status.gradient_evals;
+        // Carry the solver's gradient (if it tracks one) back into the full space,
+        // reporting zero for every fixed coordinate, so gradient-norm terminators
+        // have something to read.
+        if solver_status.gx.is_empty() {
+            self.status.gx = Vec::new();
+        } else {
+            let mut gx_full = vec![0.0f64; bounds.len()];
+            for (k, &i) in free.iter().enumerate() {
+                gx_full[i] = solver_status.gx.get(k).copied().unwrap_or(0.0);
+            }
+            self.status.gx = gx_full;
+        }
+    }
+
+    /// Run the minimization, returning the final [`Summary`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if any objective evaluation fails during the run.
+    pub fn minimize(&mut self, func: &impl CostFunction<U, E>) -> Result<&Summary, E>
+    where
+        U: Default,
+    {
+        let x0_full: Vec<Float> = self.status.x0.iter().map(|&v| v as Float).collect();
+        let n = x0_full.len();
+        let bounds = self.resolved_bounds(n);
+
+        // General constraints take the dedicated SQP path rather than the boxed
+        // solver, which cannot express them.
+        if !self.constraints.is_empty() {
+            return self.minimize_constrained(func, &bounds, &x0_full);
+        }
+
+        let free = bounds.free_indices();
+        let x0_free = bounds.to_free(&x0_full);
+
+        let projected = Projected {
+            inner: func,
+            bounds: &bounds,
+        };
+        let mut user_data = U::default();
+        self.solver
+            .initialize(&projected, &x0_free, &mut user_data)?;
+
+        self.status.message = String::from("MAX STEPS");
+        let mut aborted = false;
+        for iter in 0..self.max_steps {
+            if self
+                .abort_signal
+                .as_ref()
+                .is_some_and(|s| s.is_aborted())
+            {
+                aborted = true;
+                break;
+            }
+            self.solver.step(&projected, &mut user_data)?;
+            self.sync_status(&bounds);
+            for observer in &mut self.observers {
+                observer.observe(iter, &self.status);
+            }
+            if let Some(reason) = self
+                .terminator
+                .as_mut()
+                .and_then(|t| t.check(&self.status))
+            {
+                // A resource budget (max evals / max time) is a stop, not a
+                // successful fit, so only genuine-convergence reasons flip the flag.
+                self.status.converged = reason.is_convergence();
+                self.status.message = reason.to_string();
+                break;
+            }
+            if self.solver.converged() {
+                self.status.converged = true;
+                self.status.message = String::from("term_f = STDDEV");
+                break;
+            }
+        }
+
+        // Splice uncertainties back, reporting zero for every fixed parameter.
+        let solver_std = self.solver.status().std.clone();
+        let mut std_full = vec![0.0f64; n];
+        for (k, &i) in free.iter().enumerate() {
+            std_full[i] = solver_std.get(k).copied().unwrap_or(0.0);
+        }
+        self.status.std = std_full;
+        self.status.bounds = Some(bounds);
+        if aborted {
+            self.status.message = String::from("ABORTED");
+        }
+        Ok(&self.status)
+    }
+
+    /// Run the constrained [`SQP`] solver over the full parameter space, folding its
+    /// result (including constraint residuals and Lagrange multipliers) into the run
+    /// [`Summary`]. Any [`Bound::Fixed`] parameters are appended as synthetic
+    /// equality constraints so they are still held constant.
+    fn minimize_constrained(
+        &mut self,
+        func: &impl CostFunction<U, E>,
+        bounds: &Bounds,
+        x0_full: &[Float],
+    ) -> Result<&Summary, E>
+    where
+        U: Default,
+    {
+        let mut x0 = x0_full.to_vec();
+        let mut constraints = std::mem::take(&mut self.constraints);
+        let n_user = constraints.len();
+        for (i, b) in bounds.as_slice().iter().enumerate() {
+            if let Bound::Fixed(value) = *b {
+                x0[i] = value;
+                constraints.push(Constraint::equality(Box::new(CoordinateOffset {
+                    index: i,
+                    value,
+                })));
+            }
+        }
+
+        let sqp = SQP::default().with_max_steps(self.max_steps);
+        let mut user_data = U::default();
+        let summary = sqp.minimize(func, &constraints, &x0, &mut user_data)?;
+
+        // Fold the SQP result into the run status, keeping the original starting
+        // point and dropping the synthetic fixed-parameter constraints so only the
+        // user's constraints are reported.
+        self.status.x = summary.x;
+        self.status.fx = summary.fx;
+        self.status.std = summary.std;
+        self.status.gx = summary.gx;
+        self.status.cost_evals = summary.cost_evals;
+        self.status.gradient_evals = summary.gradient_evals;
+        self.status.message = summary.message;
+        self.status.converged = summary.converged;
+        self.status.constraint_residuals =
+            summary.constraint_residuals.into_iter().take(n_user).collect();
+        self.status.lagrange_multipliers =
+            summary.lagrange_multipliers.into_iter().take(n_user).collect();
+        self.status.bounds = Some(bounds.clone());
+        Ok(&self.status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::MaxCostEvals;
+    use crate::solvers::gradient_free::NelderMead;
+    use crate::traits::TerminationReason;
+    use std::convert::Infallible;
+
+    /// $`f(x) = (x_0 - 1)^2 + (x_1 - 2)^2`$, minimized at $`(1, 2)`$.
+    struct Paraboloid;
+    impl CostFunction<(), Infallible> for Paraboloid {
+        fn evaluate(&self, x: &[Float], _user_data: &mut ()) -> Result<Float, Infallible> {
+            Ok((x[0] - 1.0).powi(2) + (x[1] - 2.0).powi(2))
+        }
+    }
+
+    #[test]
+    fn test_fixed_parameter_held_constant() {
+        let bounds = Bounds::new(vec![Bound::NoBound, Bound::Fixed(5.0)]);
+        let mut m = Minimizer::new(NelderMead::default()).setup(|m| {
+            m.with_bounds(bounds)
+                .with_max_steps(5_000)
+                .on_status(|s| s.with_x0(vec![0.0, 0.0]))
+        });
+        m.minimize(&Paraboloid).unwrap();
+        // The free parameter reaches its optimum; the fixed one stays put.
+        assert!((m.status.x[0] - 1.0).abs() < 1e-3);
+        assert_eq!(m.status.x[1], 5.0);
+        // Fixed parameters report zero uncertainty.
+        assert_eq!(m.status.std[1], 0.0);
+    }
+
+    #[test]
+    fn test_terminator_stops_run() {
+        let mut m = Minimizer::new(NelderMead::default()).setup(|m| {
+            m.with_max_steps(1_000_000)
+                .with_terminator(MaxCostEvals::new(5))
+                .on_status(|s| s.with_x0(vec![10.0, 10.0]))
+        });
+        m.minimize(&Paraboloid).unwrap();
+        // The evaluation budget halts the run long before the simplex collapses.
+        // Exhausting a resource budget is a stop, not convergence.
+        assert!(!m.status.converged);
+        assert_eq!(m.status.message, TerminationReason::MaxCostEvals.to_string());
+        assert!(m.status.cost_evals < 1_000_000);
+    }
+
+    #[test]
+    fn test_constraint_routes_to_sqp() {
+        use crate::core::Constraint;
+        use nalgebra::DVector;
+
+        // g(x) = x_0 + x_1 - 1, an equality constraint.
+        struct Sum;
+        impl CostFunction<(), Infallible> for Sum {
+            fn evaluate(&self, x: &[Float], _user_data: &mut ()) -> Result<Float, Infallible> {
+                Ok(x[0] + x[1] - 1.0)
+            }
+            fn gradient(
+                &self,
+                _x: &[Float],
+                _user_data: &mut (),
+            ) -> Result<DVector<Float>, Infallible> {
+                Ok(DVector::from_vec(vec![1.0, 1.0]))
+            }
+        }
+
+        let mut m = Minimizer::new(NelderMead::default()).setup(|m| {
+            m.with_constraint(Constraint::equality(Box::new(Sum)))
+                .on_status(|s| s.with_x0(vec![2.0, 0.0]))
+        });
+        m.minimize(&Paraboloid).unwrap();
+        // min (x_0-1)² + (x_1-2)² s.t. x_0 + x_1 = 1  →  (0, 1).
+        assert!(m.status.converged);
+        assert!((m.status.x[0] - 0.0).abs() < 1e-4);
+        assert!((m.status.x[1] - 1.0).abs() < 1e-4);
+        assert!(m.status.constraint_residuals[0].abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_resume_from_seeds_start() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("ganesh_resume_{}.json", std::process::id()));
+        // A checkpoint left a run at (1, 2).
+        Checkpoint::write(&path, &Summary::default().with_x0(vec![1.0, 2.0])).unwrap();
+
+        let m: Minimizer<(), Infallible> = Minimizer::new(NelderMead::default())
+            .resume_from(&path)
+            .unwrap();
+        // Resuming restarts the search from the checkpointed point.
+        assert_eq!(m.status.x0, vec![1.0, 2.0]);
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -2,15 +2,26 @@
 pub mod abort_signal;
 /// [`Bound`] type for binding variables to a range.
 pub mod bound;
+/// [`Constraint`] type for general equality/inequality constraints.
+pub mod constraint;
 /// [`Minimizer`] type for the minimization process.
 pub mod minimizer;
+/// Built-in [`Observer`](crate::traits::Observer) implementations.
+pub mod observers;
 /// [`Point`] type for defining a point in the parameter space.
 pub mod point;
 /// [`Summary`] type for the result of the minimization.
 pub mod summary;
+/// Composable [`Terminator`](crate::traits::Terminator) implementations.
+pub mod terminators;
 
 pub use abort_signal::{AtomicAbortSignal, CtrlCAbortSignal, NopAbortSignal};
 pub use bound::{Bound, Bounds};
+pub use constraint::{Constraint, ConstraintKind};
 pub use minimizer::Minimizer;
+pub use observers::{Checkpoint, TableLogger, Trajectory};
 pub use point::Point;
 pub use summary::Summary;
+pub use terminators::{
+    AbsFtol, And, GradientNorm, MaxCostEvals, MaxTime, Not, Or, ParameterStep, RelFtol,
+};
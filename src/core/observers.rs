@@ -0,0 +1,114 @@
+//! Built-in [`Observer`] implementations for logging, trajectory recording, and
+//! checkpointing.
+
+use std::path::{Path, PathBuf};
+
+use crate::core::Summary;
+use crate::traits::Observer;
+
+/// An observer that logs the [`Summary`] table to standard output at each iteration.
+#[derive(Debug, Clone, Default)]
+pub struct TableLogger;
+
+impl TableLogger {
+    /// Construct a new table logger.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Observer for TableLogger {
+    fn observe(&mut self, iter: usize, status: &Summary) {
+        println!("iteration {iter}:");
+        println!("{status}");
+    }
+}
+
+/// An observer that records the full trajectory of parameters and cost values for
+/// later inspection or plotting.
+#[derive(Debug, Clone, Default)]
+pub struct Trajectory {
+    /// The parameter vector recorded at each observed iteration.
+    pub xs: Vec<Vec<f64>>,
+    /// The cost value recorded at each observed iteration.
+    pub fxs: Vec<f64>,
+}
+
+impl Trajectory {
+    /// Construct an empty trajectory recorder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            xs: Vec::new(),
+            fxs: Vec::new(),
+        }
+    }
+}
+
+impl Observer for Trajectory {
+    fn observe(&mut self, _iter: usize, status: &Summary) {
+        self.xs.push(status.x.clone());
+        self.fxs.push(status.fx);
+    }
+}
+
+/// An observer that serializes the running [`Summary`] to disk every `every`
+/// iterations, so that a long run can be resumed after an interruption via
+/// `Minimizer::resume_from`.
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    path: PathBuf,
+    every: usize,
+}
+
+impl Checkpoint {
+    /// Construct a checkpoint observer that writes to `path` every `every`
+    /// iterations.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>, every: usize) -> Self {
+        Self {
+            path: path.into(),
+            every: every.max(1),
+        }
+    }
+
+    /// Write a checkpoint of `status` to `path` immediately.
+    ///
+    /// The observer calls this for its periodic checkpoints. No checkpoint is
+    /// written automatically when a run is aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`std::io::Error`] if the status cannot be serialized or the file
+    /// cannot be written.
+    pub fn write(path: impl AsRef<Path>, status: &Summary) -> std::io::Result<()> {
+        let json = serde_json::to_string(status)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, json)
+    }
+
+    /// Load a previously written checkpoint from `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`std::io::Error`] if the file cannot be read or does not contain
+    /// a valid serialized [`Summary`].
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Summary> {
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl Observer for Checkpoint {
+    fn observe(&mut self, iter: usize, status: &Summary) {
+        if iter % self.every == 0 {
+            // A failed checkpoint write should not abort the optimization; surface
+            // it on stderr and keep going.
+            if let Err(e) = Self::write(&self.path, status) {
+                eprintln!("checkpoint write to {} failed: {e}", self.path.display());
+            }
+        }
+    }
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use super::{Bound, Bounds};
 
 /// A struct that holds the results of a minimization run.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Summary {
     /// The bounds of the parameters. This is `None` if no bounds were set.
     pub bounds: Option<Bounds>,
@@ -21,14 +21,40 @@ pub struct Summary {
     pub std: Vec<f64>,
     /// The current value of the minimization problem function at [`Summary::x`].
     pub fx: f64,
+    /// The current gradient $`\nabla f`$ at [`Summary::x`], when a gradient-based
+    /// algorithm is in use. Empty when no gradient is available. Used by
+    /// gradient-norm [`Terminator`](crate::traits::Terminator)s.
+    #[serde(default)]
+    pub gx: Vec<f64>,
     /// The number of function evaluations.
     pub cost_evals: usize,
     /// The number of gradient evaluations.
     pub gradient_evals: usize,
+    /// The final residuals $`g(x)`$ of any attached constraints, in the order they
+    /// were supplied. Empty for unconstrained problems.
+    #[serde(default)]
+    pub constraint_residuals: Vec<f64>,
+    /// The Lagrange multipliers of any attached constraints, in the order they were
+    /// supplied. A nonzero multiplier marks an active constraint. Empty for
+    /// unconstrained problems.
+    #[serde(default)]
+    pub lagrange_multipliers: Vec<f64>,
     /// Flag that says whether or not the fit is in a converged state.
     pub converged: bool,
 }
 
+impl Summary {
+    /// Set the initial parameters, seeding the current parameters to the same
+    /// point. This is the starting point a [`Minimizer`](crate::core::Minimizer)
+    /// reads when a run begins.
+    #[must_use]
+    pub fn with_x0(mut self, x0: Vec<f64>) -> Self {
+        self.x = x0.clone();
+        self.x0 = x0;
+        self
+    }
+}
+
 impl Display for Summary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use tabled::{
@@ -91,7 +117,11 @@ impl Display for Summary {
                 &format!("{:.5}", v0),
                 &format!("{:.5}", b.lower()),
                 &format!("{:.5}", b.upper()),
-                b.at_bound(*v).then_some("Yes").unwrap_or("No"),
+                if b.is_fixed() {
+                    "Fixed"
+                } else {
+                    b.at_bound(*v).then_some("Yes").unwrap_or("No")
+                },
             ]);
         }
         let mut table = builder.build();
@@ -144,8 +174,11 @@ mod tests {
             x: vec![1.0, 2.0, 3.0],
             std: vec![0.1, 0.2, 0.3],
             fx: 3.0,
+            gx: vec![],
             cost_evals: 10,
             gradient_evals: 5,
+            constraint_residuals: vec![],
+            lagrange_multipliers: vec![],
             converged: true,
         };
         println!("{}", result);
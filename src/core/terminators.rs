@@ -0,0 +1,302 @@
+//! Standard [`Terminator`] implementations and the `And`/`Or`/`Not` combinators
+//! used to build composite convergence criteria.
+
+use std::time::{Duration, Instant};
+
+use crate::core::Summary;
+use crate::traits::{TerminationReason, Terminator};
+use crate::Float;
+
+/// Stop when the absolute change in the cost between successive iterations falls
+/// below `tol`.
+#[derive(Debug, Clone)]
+pub struct AbsFtol {
+    tol: Float,
+    last: Option<Float>,
+}
+
+impl AbsFtol {
+    /// Construct the terminator with the given absolute function tolerance.
+    #[must_use]
+    pub const fn new(tol: Float) -> Self {
+        Self { tol, last: None }
+    }
+}
+
+impl Terminator for AbsFtol {
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason> {
+        let fx = status.fx as Float;
+        let reason = self
+            .last
+            .filter(|last| (last - fx).abs() < self.tol)
+            .map(|_| TerminationReason::FunctionAbsChange);
+        self.last = Some(fx);
+        reason
+    }
+}
+
+/// Stop when the relative change in the cost between successive iterations falls
+/// below `tol`.
+#[derive(Debug, Clone)]
+pub struct RelFtol {
+    tol: Float,
+    last: Option<Float>,
+}
+
+impl RelFtol {
+    /// Construct the terminator with the given relative function tolerance.
+    #[must_use]
+    pub const fn new(tol: Float) -> Self {
+        Self { tol, last: None }
+    }
+}
+
+impl Terminator for RelFtol {
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason> {
+        let fx = status.fx as Float;
+        let reason = self
+            .last
+            .filter(|last| (last - fx).abs() < self.tol * last.abs().max(Float::MIN_POSITIVE))
+            .map(|_| TerminationReason::FunctionRelChange);
+        self.last = Some(fx);
+        reason
+    }
+}
+
+/// Stop when the Euclidean norm of the parameter step falls below `tol`.
+#[derive(Debug, Clone)]
+pub struct ParameterStep {
+    tol: Float,
+    last: Option<Vec<f64>>,
+}
+
+impl ParameterStep {
+    /// Construct the terminator with the given step-norm tolerance.
+    #[must_use]
+    pub const fn new(tol: Float) -> Self {
+        Self { tol, last: None }
+    }
+}
+
+impl Terminator for ParameterStep {
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason> {
+        let reason = self.last.as_ref().and_then(|last| {
+            let step = last
+                .iter()
+                .zip(&status.x)
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum::<f64>()
+                .sqrt();
+            ((step as Float) < self.tol).then_some(TerminationReason::ParameterStep)
+        });
+        self.last = Some(status.x.clone());
+        reason
+    }
+}
+
+/// Stop when the infinity norm of the gradient falls below `tol`.
+///
+/// This reads [`Summary::gx`]; it never fires for algorithms that do not populate
+/// the gradient.
+#[derive(Debug, Clone)]
+pub struct GradientNorm {
+    tol: Float,
+}
+
+impl GradientNorm {
+    /// Construct the terminator with the given gradient-infinity-norm tolerance.
+    #[must_use]
+    pub const fn new(tol: Float) -> Self {
+        Self { tol }
+    }
+}
+
+impl Terminator for GradientNorm {
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason> {
+        if status.gx.is_empty() {
+            return None;
+        }
+        let norm = status.gx.iter().fold(0.0f64, |acc, g| acc.max(g.abs()));
+        ((norm as Float) < self.tol).then_some(TerminationReason::GradientNorm)
+    }
+}
+
+/// Stop once the number of cost evaluations reaches `max`.
+#[derive(Debug, Clone)]
+pub struct MaxCostEvals {
+    max: usize,
+}
+
+impl MaxCostEvals {
+    /// Construct the terminator with the given evaluation budget.
+    #[must_use]
+    pub const fn new(max: usize) -> Self {
+        Self { max }
+    }
+}
+
+impl Terminator for MaxCostEvals {
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason> {
+        (status.cost_evals >= self.max).then_some(TerminationReason::MaxCostEvals)
+    }
+}
+
+/// Stop once a wall-clock budget has elapsed since the first check.
+#[derive(Debug, Clone)]
+pub struct MaxTime {
+    budget: Duration,
+    start: Option<Instant>,
+}
+
+impl MaxTime {
+    /// Construct the terminator with the given wall-clock budget.
+    #[must_use]
+    pub const fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            start: None,
+        }
+    }
+}
+
+impl Terminator for MaxTime {
+    fn check(&mut self, _status: &Summary) -> Option<TerminationReason> {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        (start.elapsed() >= self.budget).then_some(TerminationReason::MaxTime)
+    }
+}
+
+/// Fires only when *both* inner terminators fire on the same iteration.
+///
+/// Because a `Terminator` holds mutable state, both children are always polled so
+/// that their internal bookkeeping stays in step.
+pub struct And(pub Box<dyn Terminator>, pub Box<dyn Terminator>);
+
+impl And {
+    /// Combine two terminators with a logical AND.
+    #[must_use]
+    pub fn new(a: Box<dyn Terminator>, b: Box<dyn Terminator>) -> Self {
+        Self(a, b)
+    }
+}
+
+impl Terminator for And {
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason> {
+        let a = self.0.check(status);
+        let b = self.1.check(status);
+        match (a, b) {
+            (Some(a), Some(b)) => Some(flatten(a, b)),
+            _ => None,
+        }
+    }
+}
+
+/// Fires when *either* inner terminator fires; reports every reason that fired.
+pub struct Or(pub Box<dyn Terminator>, pub Box<dyn Terminator>);
+
+impl Or {
+    /// Combine two terminators with a logical OR.
+    #[must_use]
+    pub fn new(a: Box<dyn Terminator>, b: Box<dyn Terminator>) -> Self {
+        Self(a, b)
+    }
+}
+
+impl Terminator for Or {
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason> {
+        let a = self.0.check(status);
+        let b = self.1.check(status);
+        match (a, b) {
+            (Some(a), Some(b)) => Some(flatten(a, b)),
+            (Some(r), None) | (None, Some(r)) => Some(r),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Inverts an inner terminator. The synthesized reason is taken from `reason`,
+/// since a negated criterion has no natural reason of its own.
+pub struct Not {
+    inner: Box<dyn Terminator>,
+    reason: TerminationReason,
+}
+
+impl Not {
+    /// Negate `inner`, reporting `reason` when the negation fires.
+    #[must_use]
+    pub fn new(inner: Box<dyn Terminator>, reason: TerminationReason) -> Self {
+        Self { inner, reason }
+    }
+}
+
+impl Terminator for Not {
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason> {
+        self.inner
+            .check(status)
+            .is_none()
+            .then(|| self.reason.clone())
+    }
+}
+
+/// Merge two reasons into a single [`TerminationReason::Multiple`], flattening any
+/// nesting so simultaneous criteria appear as one flat list.
+fn flatten(a: TerminationReason, b: TerminationReason) -> TerminationReason {
+    let mut reasons = Vec::new();
+    for r in [a, b] {
+        match r {
+            TerminationReason::Multiple(inner) => reasons.extend(inner),
+            other => reasons.push(other),
+        }
+    }
+    TerminationReason::Multiple(reasons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_with_fx(fx: f64, cost_evals: usize) -> Summary {
+        Summary {
+            bounds: None,
+            parameter_names: None,
+            message: String::new(),
+            x0: vec![],
+            x: vec![],
+            std: vec![],
+            fx,
+            gx: vec![],
+            cost_evals,
+            gradient_evals: 0,
+            constraint_residuals: vec![],
+            lagrange_multipliers: vec![],
+            converged: false,
+        }
+    }
+
+    #[test]
+    fn test_abs_ftol_fires_on_small_change() {
+        let mut term = AbsFtol::new(1e-6);
+        assert!(term.check(&status_with_fx(1.0, 1)).is_none());
+        assert_eq!(
+            term.check(&status_with_fx(1.0 + 1e-9, 2)),
+            Some(TerminationReason::FunctionAbsChange)
+        );
+    }
+
+    #[test]
+    fn test_or_reports_both_reasons() {
+        let mut term = Or::new(
+            Box::new(MaxCostEvals::new(1)),
+            Box::new(GradientNorm::new(1.0)),
+        );
+        let mut status = status_with_fx(1.0, 5);
+        status.gx = vec![0.1, -0.2];
+        assert_eq!(
+            term.check(&status),
+            Some(TerminationReason::Multiple(vec![
+                TerminationReason::MaxCostEvals,
+                TerminationReason::GradientNorm,
+            ]))
+        );
+    }
+}
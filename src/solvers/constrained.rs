@@ -0,0 +1,379 @@
+//! Constrained minimization via a sequential quadratic programming (SQP) solver in
+//! the spirit of SLSQP.
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::core::{Constraint, ConstraintKind, Summary};
+use crate::traits::CostFunction;
+use crate::Float;
+
+/// A sequential quadratic programming solver for problems with general equality
+/// and inequality [`Constraint`]s.
+///
+/// At each iterate the solver builds a quadratic model of the Lagrangian with a
+/// BFGS-updated Hessian approximation and linearized constraints, solves the
+/// resulting equality-constrained QP subproblem (with the currently active
+/// inequalities treated as equalities) for a step direction, and takes a
+/// backtracking line search on an $`\ell_1`$ merit function that penalizes
+/// constraint violation. The final constraint residuals and Lagrange multipliers
+/// are reported in the [`Summary`].
+#[derive(Debug, Clone)]
+pub struct SQP {
+    max_steps: usize,
+    tol: Float,
+}
+
+impl Default for SQP {
+    fn default() -> Self {
+        Self {
+            max_steps: 200,
+            tol: 1e-8,
+        }
+    }
+}
+
+impl SQP {
+    /// Set the maximum number of SQP iterations.
+    #[must_use]
+    pub const fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Set the convergence tolerance on the step norm and constraint violation.
+    #[must_use]
+    pub const fn with_tolerance(mut self, tol: Float) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Minimize `function` subject to `constraints`, starting from `x0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if any objective or constraint evaluation fails.
+    #[allow(clippy::too_many_lines)]
+    pub fn minimize<U, E>(
+        &self,
+        function: &impl CostFunction<U, E>,
+        constraints: &[Constraint<U, E>],
+        x0: &[Float],
+        user_data: &mut U,
+    ) -> Result<Summary, E> {
+        let n = x0.len();
+        let m = constraints.len();
+        let mut x = DVector::from_row_slice(x0);
+        let mut hess = DMatrix::<Float>::identity(n, n);
+        let mut penalty = 10.0;
+        let mut cost_evals = 0;
+        let mut gradient_evals = 0;
+
+        let mut multipliers = DVector::<Float>::zeros(m);
+        let mut converged = false;
+        let mut message = String::from("MAX STEPS");
+
+        // Gradient of the Lagrangian ∇f + Σ μ_i ∇g_i, matching the `+` multiplier
+        // convention of the KKT solve below (L = f + Σ μ_i g_i).
+        let lagrangian_grad =
+            |x: &DVector<Float>,
+             lam: &DVector<Float>,
+             ce: &mut usize,
+             ge: &mut usize,
+             ud: &mut U|
+             -> Result<DVector<Float>, E> {
+                let mut g = function.gradient(x.as_slice(), ud)?;
+                *ge += 1;
+                for (i, con) in constraints.iter().enumerate() {
+                    let gi = con.gradient(x.as_slice(), ud)?;
+                    *ce += 1;
+                    g += lam[i] * gi;
+                }
+                Ok(g)
+            };
+
+        for _ in 0..self.max_steps {
+            let grad_f = function.gradient(x.as_slice(), user_data)?;
+            gradient_evals += 1;
+
+            // Constraint values and gradients at the current iterate.
+            let mut residuals = DVector::zeros(m);
+            let mut jac = DMatrix::zeros(m, n);
+            for (i, con) in constraints.iter().enumerate() {
+                residuals[i] = con.residual(x.as_slice(), user_data)?;
+                cost_evals += 1;
+                let gi = con.gradient(x.as_slice(), user_data)?;
+                gradient_evals += 1;
+                jac.set_row(i, &gi.transpose());
+            }
+
+            // Active set: equalities are always active; an inequality starts active
+            // if it is violated or near its boundary. Inequalities whose multiplier
+            // returns negative are pushing the step the wrong way, so they are
+            // dropped and the QP re-solved — the SLSQP-like active-set strategy.
+            let mut active: Vec<usize> = (0..m)
+                .filter(|&i| match constraints[i].kind {
+                    ConstraintKind::Equality => true,
+                    ConstraintKind::Inequality => residuals[i] >= -self.tol,
+                })
+                .collect();
+
+            let mut d = DVector::zeros(n);
+            multipliers = DVector::zeros(m);
+            let mut singular = false;
+            // At most one constraint is dropped per pass, so `m + 1` passes suffice.
+            for _ in 0..=m {
+                // Solve the KKT system for the step d and active multipliers μ:
+                //   [H  Aᵀ][ d ]   [ -∇f ]
+                //   [A  0 ][ μ ] = [ -c  ]
+                let na = active.len();
+                let mut kkt = DMatrix::zeros(n + na, n + na);
+                kkt.view_mut((0, 0), (n, n)).copy_from(&hess);
+                let mut rhs = DVector::zeros(n + na);
+                rhs.view_mut((0, 0), (n, 1)).copy_from(&(-&grad_f));
+                for (k, &i) in active.iter().enumerate() {
+                    let row = jac.row(i);
+                    kkt.view_mut((n + k, 0), (1, n)).copy_from(&row);
+                    kkt.view_mut((0, n + k), (n, 1)).copy_from(&row.transpose());
+                    rhs[n + k] = -residuals[i];
+                }
+
+                let Some(sol) = kkt.lu().solve(&rhs) else {
+                    singular = true;
+                    break;
+                };
+                d = DVector::from_column_slice(&sol.as_slice()[..n]);
+
+                // Scatter the active multipliers back into the full vector.
+                multipliers = DVector::zeros(m);
+                for (k, &i) in active.iter().enumerate() {
+                    multipliers[i] = sol[n + k];
+                }
+
+                // Drop the active inequality with the most negative multiplier, if
+                // any; otherwise the active set is optimal for this QP.
+                let worst = active
+                    .iter()
+                    .copied()
+                    .filter(|&i| constraints[i].kind == ConstraintKind::Inequality)
+                    .filter(|&i| multipliers[i] < -self.tol)
+                    .min_by(|&a, &b| {
+                        multipliers[a]
+                            .partial_cmp(&multipliers[b])
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    });
+                match worst {
+                    Some(i) => active.retain(|&j| j != i),
+                    None => break,
+                }
+            }
+            if singular {
+                message = String::from("SINGULAR KKT SYSTEM");
+                break;
+            }
+
+            // Keep the L1 penalty above the largest multiplier magnitude so the
+            // merit function is exact.
+            let lam_inf = multipliers.iter().fold(0.0, |acc, v| Float::max(acc, v.abs()));
+            penalty = penalty.max(lam_inf + 1.0);
+
+            let merit = |f: Float, res: &DVector<Float>| -> Float {
+                let viol: Float = constraints
+                    .iter()
+                    .zip(res.iter())
+                    .map(|(con, &r)| con.violation(r))
+                    .sum();
+                f + penalty * viol
+            };
+
+            let f0 = function.evaluate(x.as_slice(), user_data)?;
+            cost_evals += 1;
+            let phi0 = merit(f0, &residuals);
+
+            // Backtracking line search on the L1 merit function.
+            let mut alpha = 1.0;
+            let mut x_new = &x + &d;
+            let mut accepted = false;
+            for _ in 0..30 {
+                x_new = &x + alpha * &d;
+                let f_new = function.evaluate(x_new.as_slice(), user_data)?;
+                cost_evals += 1;
+                let mut res_new = DVector::zeros(m);
+                for (i, con) in constraints.iter().enumerate() {
+                    res_new[i] = con.residual(x_new.as_slice(), user_data)?;
+                    cost_evals += 1;
+                }
+                if merit(f_new, &res_new) < phi0 {
+                    accepted = true;
+                    break;
+                }
+                alpha *= 0.5;
+            }
+            if !accepted {
+                // No merit decrease: accept the damped step and let the next
+                // iteration re-linearize.
+                x_new = &x + alpha * &d;
+            }
+
+            // Damped BFGS update of the Lagrangian Hessian.
+            let g_old = lagrangian_grad(
+                &x,
+                &multipliers,
+                &mut cost_evals,
+                &mut gradient_evals,
+                user_data,
+            )?;
+            let g_new = lagrangian_grad(
+                &x_new,
+                &multipliers,
+                &mut cost_evals,
+                &mut gradient_evals,
+                user_data,
+            )?;
+            let s = &x_new - &x;
+            let y = &g_new - &g_old;
+            bfgs_update(&mut hess, &s, &y);
+
+            let step_norm = s.norm();
+            x = x_new;
+
+            let violation: Float = constraints
+                .iter()
+                .enumerate()
+                .map(|(i, con)| con.violation(residuals[i]))
+                .sum();
+            if step_norm < self.tol && violation < self.tol {
+                converged = true;
+                message = String::from("STEP & FEASIBILITY < TOL");
+                break;
+            }
+        }
+
+        // Final objective and constraint residuals.
+        let fx = function.evaluate(x.as_slice(), user_data)?;
+        cost_evals += 1;
+        let mut constraint_residuals = Vec::with_capacity(m);
+        for con in constraints {
+            constraint_residuals.push(con.residual(x.as_slice(), user_data)? as f64);
+            cost_evals += 1;
+        }
+
+        Ok(Summary {
+            bounds: None,
+            parameter_names: None,
+            message,
+            x0: x0.to_vec(),
+            x: x.iter().copied().collect(),
+            std: vec![0.0; n],
+            fx: fx as f64,
+            gx: vec![],
+            cost_evals,
+            gradient_evals,
+            constraint_residuals,
+            lagrange_multipliers: multipliers.iter().copied().collect(),
+            converged,
+        })
+    }
+}
+
+/// Damped BFGS update of a Hessian approximation, following Powell's modification
+/// so that `hess` stays positive definite even when $`s^\top y`$ is small.
+fn bfgs_update(hess: &mut DMatrix<Float>, s: &DVector<Float>, y: &DVector<Float>) {
+    let hs = &*hess * s;
+    let s_hs = s.dot(&hs);
+    if s_hs <= 0.0 {
+        return;
+    }
+    let sy = s.dot(y);
+    let theta = if sy >= 0.2 * s_hs {
+        1.0
+    } else {
+        0.8 * s_hs / (s_hs - sy)
+    };
+    let r = theta * y + (1.0 - theta) * &hs;
+    let sr = s.dot(&r);
+    if sr.abs() < Float::EPSILON {
+        return;
+    }
+    *hess += (&r * r.transpose()) / sr - (&hs * hs.transpose()) / s_hs;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::DVector;
+    use std::convert::Infallible;
+
+    /// Objective $`f(x) = x_0^2 + x_1^2`$.
+    struct Quadratic;
+    impl CostFunction<(), Infallible> for Quadratic {
+        fn evaluate(&self, x: &[Float], _user_data: &mut ()) -> Result<Float, Infallible> {
+            Ok(x[0] * x[0] + x[1] * x[1])
+        }
+        fn gradient(&self, x: &[Float], _user_data: &mut ()) -> Result<DVector<Float>, Infallible> {
+            Ok(DVector::from_vec(vec![2.0 * x[0], 2.0 * x[1]]))
+        }
+    }
+
+    /// Constraint function $`g(x) = x_0 + x_1 - 1`$.
+    struct SumMinusOne;
+    impl CostFunction<(), Infallible> for SumMinusOne {
+        fn evaluate(&self, x: &[Float], _user_data: &mut ()) -> Result<Float, Infallible> {
+            Ok(x[0] + x[1] - 1.0)
+        }
+        fn gradient(&self, _x: &[Float], _user_data: &mut ()) -> Result<DVector<Float>, Infallible> {
+            Ok(DVector::from_vec(vec![1.0, 1.0]))
+        }
+    }
+
+    /// Constraint function $`g(x) = 1 - x_0 - x_1`$ (i.e. $`x_0 + x_1 \ge 1`$).
+    struct OneMinusSum;
+    impl CostFunction<(), Infallible> for OneMinusSum {
+        fn evaluate(&self, x: &[Float], _user_data: &mut ()) -> Result<Float, Infallible> {
+            Ok(1.0 - x[0] - x[1])
+        }
+        fn gradient(&self, _x: &[Float], _user_data: &mut ()) -> Result<DVector<Float>, Infallible> {
+            Ok(DVector::from_vec(vec![-1.0, -1.0]))
+        }
+    }
+
+    #[test]
+    fn test_equality_constrained_quadratic() {
+        // min x² + y² s.t. x + y = 1  →  (0.5, 0.5), multiplier -1.
+        let constraints = vec![Constraint::equality(Box::new(SumMinusOne))];
+        let summary = SQP::default()
+            .minimize(&Quadratic, &constraints, &[2.0, 0.0], &mut ())
+            .unwrap();
+        assert!(summary.converged);
+        assert!((summary.x[0] - 0.5).abs() < 1e-5);
+        assert!((summary.x[1] - 0.5).abs() < 1e-5);
+        assert!(summary.constraint_residuals[0].abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_active_inequality() {
+        // min x² + y² s.t. x + y ≥ 1  →  boundary solution (0.5, 0.5).
+        let constraints = vec![Constraint::inequality(Box::new(OneMinusSum))];
+        let summary = SQP::default()
+            .minimize(&Quadratic, &constraints, &[2.0, 2.0], &mut ())
+            .unwrap();
+        assert!(summary.converged);
+        assert!((summary.x[0] + summary.x[1] - 1.0).abs() < 1e-5);
+        // An active inequality has a strictly positive multiplier in this sign
+        // convention.
+        assert!(summary.lagrange_multipliers[0] > 0.0);
+    }
+
+    #[test]
+    fn test_inactive_inequality_is_dropped() {
+        // min x² + y² s.t. x + y ≤ 1 is satisfied by the unconstrained min (0, 0),
+        // so the constraint is inactive and its multiplier is zero.
+        let constraints = vec![Constraint::inequality(Box::new(SumMinusOne))];
+        let summary = SQP::default()
+            .minimize(&Quadratic, &constraints, &[2.0, 2.0], &mut ())
+            .unwrap();
+        assert!(summary.converged);
+        assert!(summary.x[0].abs() < 1e-4);
+        assert!(summary.x[1].abs() < 1e-4);
+        assert!(summary.lagrange_multipliers[0].abs() < 1e-6);
+    }
+}
@@ -0,0 +1,193 @@
+//! Gradient-free minimization algorithms.
+
+use nalgebra::DVector;
+
+use crate::core::Summary;
+use crate::traits::{CostFunction, Solver};
+use crate::Float;
+
+/// The Nelder-Mead downhill simplex method.
+///
+/// A derivative-free method that maintains a simplex of $`n + 1`$ vertices and
+/// reflects, expands, contracts or shrinks it toward a minimum. The adaptive
+/// variant of Gao and Han keeps the reflection coefficient at 1 and scales the
+/// expansion/contraction/shrink coefficients with the dimension, which improves
+/// robustness in higher dimensions.
+#[derive(Debug, Clone)]
+pub struct NelderMead {
+    alpha: Float,
+    gamma: Float,
+    rho: Float,
+    sigma: Float,
+    tol: Float,
+    simplex_size: Float,
+    simplex: Vec<(DVector<Float>, Float)>,
+    status: Summary,
+}
+
+impl Default for NelderMead {
+    fn default() -> Self {
+        Self {
+            alpha: 1.0,
+            gamma: 2.0,
+            rho: 0.5,
+            sigma: 0.5,
+            tol: 1e-8,
+            simplex_size: 1.0,
+            simplex: Vec::new(),
+            status: Summary::default(),
+        }
+    }
+}
+
+impl NelderMead {
+    /// Use the dimension-adaptive coefficients of Gao and Han for a problem of
+    /// dimension `n`.
+    #[must_use]
+    pub fn with_adaptive(mut self, n: usize) -> Self {
+        let n = n.max(1) as Float;
+        self.alpha = 1.0;
+        self.gamma = 1.0 + 2.0 / n;
+        self.rho = 0.75 - 1.0 / (2.0 * n);
+        self.sigma = 1.0 - 1.0 / n;
+        self
+    }
+
+    /// Set the convergence tolerance on the spread of the simplex's cost values.
+    #[must_use]
+    pub const fn with_tolerance(mut self, tol: Float) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Set the size of the initial simplex: each coordinate of the starting point
+    /// is perturbed by this fraction of its magnitude, or by this absolute amount
+    /// where the coordinate is zero.
+    #[must_use]
+    pub const fn with_simplex_size(mut self, size: Float) -> Self {
+        self.simplex_size = size;
+        self
+    }
+
+    /// Sort the simplex so its best vertex is first, and refresh the status with it.
+    fn sort_and_record(&mut self) {
+        self.simplex
+            .sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        let (best_x, best_f) = &self.simplex[0];
+        self.status.x = best_x.iter().copied().collect();
+        self.status.fx = *best_f;
+    }
+
+    /// The centroid of every vertex except the worst.
+    fn centroid(&self) -> DVector<Float> {
+        let n = self.simplex.len() - 1;
+        let mut c = DVector::zeros(self.simplex[0].0.len());
+        for (v, _) in &self.simplex[..n] {
+            c += v;
+        }
+        c / n as Float
+    }
+}
+
+impl<U, E> Solver<U, E> for NelderMead {
+    fn initialize(
+        &mut self,
+        func: &dyn CostFunction<U, E>,
+        x0: &[Float],
+        user_data: &mut U,
+    ) -> Result<(), E> {
+        let n = x0.len();
+        let base = DVector::from_row_slice(x0);
+        self.simplex = Vec::with_capacity(n + 1);
+        let f0 = func.evaluate(base.as_slice(), user_data)?;
+        self.status.cost_evals += 1;
+        self.simplex.push((base.clone(), f0));
+        for i in 0..n {
+            let mut v = base.clone();
+            // Perturb each coordinate, falling back to the simplex size at zero.
+            v[i] += if v[i] == 0.0 {
+                self.simplex_size
+            } else {
+                self.simplex_size * v[i].abs()
+            };
+            let f = func.evaluate(v.as_slice(), user_data)?;
+            self.status.cost_evals += 1;
+            self.simplex.push((v, f));
+        }
+        self.sort_and_record();
+        Ok(())
+    }
+
+    fn step(&mut self, func: &dyn CostFunction<U, E>, user_data: &mut U) -> Result<(), E> {
+        let last = self.simplex.len() - 1;
+        let centroid = self.centroid();
+        let worst = self.simplex[last].0.clone();
+        let f_best = self.simplex[0].1;
+        let f_second_worst = self.simplex[last - 1].1;
+        let f_worst = self.simplex[last].1;
+
+        // Reflection.
+        let reflected = &centroid + self.alpha * (&centroid - &worst);
+        let f_reflected = func.evaluate(reflected.as_slice(), user_data)?;
+        self.status.cost_evals += 1;
+
+        if f_reflected < f_best {
+            // Expansion.
+            let expanded = &centroid + self.gamma * (&reflected - &centroid);
+            let f_expanded = func.evaluate(expanded.as_slice(), user_data)?;
+            self.status.cost_evals += 1;
+            self.simplex[last] = if f_expanded < f_reflected {
+                (expanded, f_expanded)
+            } else {
+                (reflected, f_reflected)
+            };
+        } else if f_reflected < f_second_worst {
+            self.simplex[last] = (reflected, f_reflected);
+        } else {
+            // Contraction, on whichever side of the centroid is lower.
+            let (contracted, f_contracted, improved) = if f_reflected < f_worst {
+                let c = &centroid + self.rho * (&reflected - &centroid);
+                let fc = func.evaluate(c.as_slice(), user_data)?;
+                self.status.cost_evals += 1;
+                (c, fc, fc <= f_reflected)
+            } else {
+                let c = &centroid + self.rho * (&worst - &centroid);
+                let fc = func.evaluate(c.as_slice(), user_data)?;
+                self.status.cost_evals += 1;
+                (c, fc, fc < f_worst)
+            };
+            if improved {
+                self.simplex[last] = (contracted, f_contracted);
+            } else {
+                // Shrink every vertex toward the best.
+                let best = self.simplex[0].0.clone();
+                for (v, fv) in self.simplex.iter_mut().skip(1) {
+                    *v = &best + self.sigma * (&*v - &best);
+                    *fv = func.evaluate(v.as_slice(), user_data)?;
+                    self.status.cost_evals += 1;
+                }
+            }
+        }
+        self.sort_and_record();
+        Ok(())
+    }
+
+    fn status(&self) -> &Summary {
+        &self.status
+    }
+
+    fn converged(&self) -> bool {
+        if self.simplex.len() < 2 {
+            return false;
+        }
+        let n = self.simplex.len() as Float;
+        let mean = self.simplex.iter().map(|(_, f)| f).sum::<Float>() / n;
+        let var = self
+            .simplex
+            .iter()
+            .map(|(_, f)| (f - mean).powi(2))
+            .sum::<Float>()
+            / n;
+        var.sqrt() < self.tol
+    }
+}
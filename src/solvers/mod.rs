@@ -0,0 +1,6 @@
+/// Constrained minimization algorithms.
+pub mod constrained;
+/// Gradient-free minimization algorithms.
+pub mod gradient_free;
+/// Nonlinear least-squares algorithms.
+pub mod least_squares;
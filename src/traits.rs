@@ -0,0 +1,249 @@
+use std::fmt::Display;
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::core::Summary;
+use crate::Float;
+
+/// The central finite-difference step size, $`\sqrt[3]{\epsilon}`$, chosen to
+/// balance truncation and round-off error for a second-order scheme.
+pub(crate) const EPSILON_CBRT: Float = 6.055_454_452_393_343e-6;
+
+/// Central finite-difference gradient of a scalar function at `x`, using the
+/// [`EPSILON_CBRT`] step scaled per component. This is the single implementation
+/// behind both the default [`CostFunction::gradient`] and the gradient-checking
+/// utilities in [`utils`](crate::utils).
+pub(crate) fn central_difference<U, E>(
+    mut f: impl FnMut(&[Float], &mut U) -> Result<Float, E>,
+    x: &[Float],
+    user_data: &mut U,
+) -> Result<DVector<Float>, E> {
+    let n = x.len();
+    let mut grad = DVector::zeros(n);
+    let mut xp = x.to_vec();
+    for i in 0..n {
+        let h = EPSILON_CBRT * x[i].abs().max(1.0);
+        xp[i] = x[i] + h;
+        let fp = f(&xp, user_data)?;
+        xp[i] = x[i] - h;
+        let fm = f(&xp, user_data)?;
+        xp[i] = x[i];
+        grad[i] = (fp - fm) / (2.0 * h);
+    }
+    Ok(grad)
+}
+
+/// A trait representing a scalar cost function $`f(\mathbb{R}^n) \to \mathbb{R}`$
+/// to be minimized.
+///
+/// Users implement [`CostFunction::evaluate`] on some struct holding whatever
+/// data the function needs. A central finite-difference [`CostFunction::gradient`]
+/// is provided out of the box, but may be overridden with an analytic version to
+/// speed up gradient-based algorithms.
+pub trait CostFunction<U, E> {
+    /// Evaluate the function at the parameters `x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if the user function fails to evaluate at `x`.
+    fn evaluate(&self, x: &[Float], user_data: &mut U) -> Result<Float, E>;
+
+    /// Compute the gradient $`\nabla f(x)`$ via a central finite difference.
+    ///
+    /// Override this with an analytic gradient where one is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if any of the underlying [`CostFunction::evaluate`] calls fail.
+    fn gradient(&self, x: &[Float], user_data: &mut U) -> Result<DVector<Float>, E> {
+        central_difference(|xp, ud| self.evaluate(xp, ud), x, user_data)
+    }
+}
+
+/// A trait representing a nonlinear least-squares problem, expressed through its
+/// vector of residuals $`r_i(x)`$ rather than a single scalar.
+///
+/// This is the residual-based counterpart to [`CostFunction`]: where the latter
+/// collapses a fit down to one number, [`LeastSquaresFunction`] exposes the
+/// individual residuals so that structure-exploiting solvers (such as
+/// [`LevenbergMarquardt`](crate::solvers::least_squares::LevenbergMarquardt)) can
+/// form the Jacobian and apply robust loss functions per residual.
+pub trait LeastSquaresFunction<U, E> {
+    /// Evaluate the residual vector $`r(x) = (r_0(x), \dots, r_{m-1}(x))`$.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if the user function fails to evaluate at `x`.
+    fn residuals(&self, x: &[Float], user_data: &mut U) -> Result<Vec<Float>, E>;
+
+    /// Compute the Jacobian $`J_{ij} = \partial r_i / \partial x_j`$ via a central
+    /// finite difference.
+    ///
+    /// Override this with an analytic Jacobian where one is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if any of the underlying [`LeastSquaresFunction::residuals`]
+    /// calls fail.
+    fn jacobian(&self, x: &[Float], user_data: &mut U) -> Result<DMatrix<Float>, E> {
+        let n = x.len();
+        let m = self.residuals(x, user_data)?.len();
+        let mut jac = DMatrix::zeros(m, n);
+        let mut xp = x.to_vec();
+        for j in 0..n {
+            let h = EPSILON_CBRT * x[j].abs().max(1.0);
+            xp[j] = x[j] + h;
+            let rp = self.residuals(&xp, user_data)?;
+            xp[j] = x[j] - h;
+            let rm = self.residuals(&xp, user_data)?;
+            xp[j] = x[j];
+            for i in 0..m {
+                jac[(i, j)] = (rp[i] - rm[i]) / (2.0 * h);
+            }
+        }
+        Ok(jac)
+    }
+}
+
+/// The reason a minimization run was stopped, as decided by a [`Terminator`].
+///
+/// Several criteria can fire on the same iteration; [`TerminationReason::Multiple`]
+/// collects them so the printed [`Summary`] explains every criterion that was met,
+/// rather than only the first one found.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TerminationReason {
+    /// The absolute change in the cost between iterations fell below a tolerance.
+    FunctionAbsChange,
+    /// The relative change in the cost between iterations fell below a tolerance.
+    FunctionRelChange,
+    /// The norm of the parameter step fell below a tolerance.
+    ParameterStep,
+    /// The infinity norm of the gradient fell below a tolerance.
+    GradientNorm,
+    /// The maximum number of cost evaluations was reached.
+    MaxCostEvals,
+    /// The maximum wall-clock time was exceeded.
+    MaxTime,
+    /// More than one criterion fired on the same iteration.
+    Multiple(Vec<TerminationReason>),
+}
+
+impl TerminationReason {
+    /// Whether this reason represents genuine convergence rather than a resource
+    /// budget being exhausted. Hitting [`TerminationReason::MaxCostEvals`] or
+    /// [`TerminationReason::MaxTime`] is a *stop*, not a successful fit, so a caller
+    /// inspecting [`Summary::converged`] is not misled. A
+    /// [`TerminationReason::Multiple`] counts as convergence if any of its reasons
+    /// do.
+    #[must_use]
+    pub fn is_convergence(&self) -> bool {
+        match self {
+            Self::FunctionAbsChange
+            | Self::FunctionRelChange
+            | Self::ParameterStep
+            | Self::GradientNorm => true,
+            Self::MaxCostEvals | Self::MaxTime => false,
+            Self::Multiple(reasons) => reasons.iter().any(Self::is_convergence),
+        }
+    }
+}
+
+impl Display for TerminationReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FunctionAbsChange => f.write_str("F_ABS"),
+            Self::FunctionRelChange => f.write_str("F_REL"),
+            Self::ParameterStep => f.write_str("X_STEP"),
+            Self::GradientNorm => f.write_str("GRAD"),
+            Self::MaxCostEvals => f.write_str("MAX_FCN"),
+            Self::MaxTime => f.write_str("MAX_TIME"),
+            Self::Multiple(reasons) => {
+                let joined = reasons
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" & ");
+                write!(f, "{joined}")
+            }
+        }
+    }
+}
+
+/// A stackable convergence criterion.
+///
+/// A `Terminator` inspects the running [`Summary`] after each iteration and returns
+/// `Some(reason)` when optimization should stop. Implementors may keep internal
+/// state (for example the previous cost) across calls, hence the `&mut self`
+/// receiver. Terminators compose through the [`And`](crate::core::And),
+/// [`Or`](crate::core::Or), and [`Not`](crate::core::Not) combinators, letting users
+/// build expressions such as "stop when ΔF < 1e-8 OR max evals reached".
+pub trait Terminator {
+    /// Inspect `status` and return the reason to stop, or `None` to continue.
+    fn check(&mut self, status: &Summary) -> Option<TerminationReason>;
+}
+
+/// A hook invoked by a [`Minimizer`](crate::core::Minimizer) after every iteration
+/// of its solver.
+///
+/// Observers let users watch or record an optimization without modifying algorithm
+/// code — they are registered through `Minimizer::setup` and called with the
+/// iteration index and the running [`Summary`]. Built-in observers are provided in
+/// [`core`](crate::core): a table logger, a trajectory recorder, and a
+/// [`Checkpoint`](crate::core::Checkpoint) that periodically serializes the
+/// [`Summary`] to disk for later resumption.
+pub trait Observer {
+    /// Called once per iteration with the current iteration index and status.
+    fn observe(&mut self, iter: usize, status: &Summary);
+}
+
+/// A minimization algorithm driven iteration-by-iteration by a
+/// [`Minimizer`](crate::core::Minimizer).
+///
+/// A solver works purely in the space of parameters it is handed, which the
+/// [`Minimizer`](crate::core::Minimizer) may have already reduced to the free
+/// (non-fixed) subspace. It owns a running [`Summary`] exposing its current best
+/// point so the driver can report progress, run observers and consult terminators.
+pub trait Solver<U, E> {
+    /// Prepare internal state for a problem starting at `x0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if an evaluation made while initializing fails.
+    fn initialize(
+        &mut self,
+        func: &dyn CostFunction<U, E>,
+        x0: &[Float],
+        user_data: &mut U,
+    ) -> Result<(), E>;
+
+    /// Advance the solver by a single iteration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `E` if an evaluation made during the step fails.
+    fn step(&mut self, func: &dyn CostFunction<U, E>, user_data: &mut U) -> Result<(), E>;
+
+    /// The current status, with the best point the solver has found so far.
+    fn status(&self) -> &Summary;
+
+    /// Whether the solver considers itself internally converged.
+    fn converged(&self) -> bool;
+}
+
+/// A cooperative abort signal that a [`Minimizer`](crate::core::Minimizer) polls
+/// between iterations so long runs can be interrupted cleanly.
+pub trait AbortSignal {
+    /// Whether an abort has been requested.
+    fn is_aborted(&self) -> bool;
+    /// Request an abort.
+    fn abort(&self);
+    /// Clear a previously requested abort.
+    fn reset(&self);
+    /// Box the signal for storage behind a trait object.
+    fn boxed(self) -> Box<dyn AbortSignal>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
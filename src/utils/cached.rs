@@ -0,0 +1,219 @@
+//! A memoizing adapter that caches cost and gradient evaluations.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+
+use nalgebra::DVector;
+
+use crate::traits::CostFunction;
+use crate::Float;
+
+/// A bit-exact cache key derived from a parameter slice.
+type Key = Vec<u64>;
+
+fn key_of(x: &[Float]) -> Key {
+    x.iter().map(|v| u64::from(v.to_bits())).collect()
+}
+
+struct Entry {
+    value: Float,
+    gradient: Option<DVector<Float>>,
+}
+
+struct CacheState {
+    capacity: usize,
+    entries: HashMap<Key, Entry>,
+    /// Recency order; the front is the least-recently-used key.
+    order: VecDeque<Key>,
+    hits: usize,
+    misses: usize,
+}
+
+impl CacheState {
+    fn touch(&mut self, key: &Key) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let k = self.order.remove(pos).unwrap_or_default();
+            self.order.push_back(k);
+        }
+    }
+
+    fn insert_value(&mut self, key: Key, value: Float) {
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.value = value;
+            self.touch(&key);
+            return;
+        }
+        self.evict_if_full();
+        self.order.push_back(key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                value,
+                gradient: None,
+            },
+        );
+    }
+
+    fn insert_gradient(&mut self, key: Key, value: Float, gradient: DVector<Float>) {
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.value = value;
+            entry.gradient = Some(gradient);
+            self.touch(&key);
+            return;
+        }
+        self.evict_if_full();
+        self.order.push_back(key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                value,
+                gradient: Some(gradient),
+            },
+        );
+    }
+
+    fn evict_if_full(&mut self) {
+        while self.entries.len() >= self.capacity {
+            if let Some(lru) = self.order.pop_front() {
+                self.entries.remove(&lru);
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+/// An adapter that wraps any [`CostFunction`] and memoizes its evaluations, backed
+/// by a bounded least-recently-used cache.
+///
+/// Algorithms such as Nelder-Mead and finite-difference gradients frequently
+/// re-evaluate at identical points (for example a reflected simplex vertex reused
+/// on the next iteration), so caching avoids redundant calls to costly user
+/// functions. Entries are keyed by the bit-exact representation of the parameter
+/// slice and store both the scalar value and any computed gradient. Because the
+/// [`Summary`](crate::core::Summary) already tracks evaluation counts, the cache
+/// exposes [`Cached::hits`]/[`Cached::misses`] separately so those counts stay
+/// meaningful.
+pub struct Cached<F> {
+    inner: F,
+    state: RefCell<CacheState>,
+}
+
+impl<F> Cached<F> {
+    /// Wrap `inner` in a cache of the given `capacity` (in number of entries).
+    #[must_use]
+    pub fn new(inner: F, capacity: usize) -> Self {
+        Self {
+            inner,
+            state: RefCell::new(CacheState {
+                capacity: capacity.max(1),
+                entries: HashMap::new(),
+                order: VecDeque::new(),
+                hits: 0,
+                misses: 0,
+            }),
+        }
+    }
+
+    /// The number of evaluations served from the cache.
+    #[must_use]
+    pub fn hits(&self) -> usize {
+        self.state.borrow().hits
+    }
+
+    /// The number of evaluations that missed the cache and called the wrapped
+    /// function.
+    #[must_use]
+    pub fn misses(&self) -> usize {
+        self.state.borrow().misses
+    }
+
+    /// A reference to the wrapped function.
+    pub const fn inner(&self) -> &F {
+        &self.inner
+    }
+}
+
+impl<U, E, F> CostFunction<U, E> for Cached<F>
+where
+    F: CostFunction<U, E>,
+{
+    fn evaluate(&self, x: &[Float], user_data: &mut U) -> Result<Float, E> {
+        let key = key_of(x);
+        {
+            let mut state = self.state.borrow_mut();
+            if let Some(entry) = state.entries.get(&key) {
+                let value = entry.value;
+                state.hits += 1;
+                state.touch(&key);
+                return Ok(value);
+            }
+            state.misses += 1;
+        }
+        let value = self.inner.evaluate(x, user_data)?;
+        self.state.borrow_mut().insert_value(key, value);
+        Ok(value)
+    }
+
+    fn gradient(&self, x: &[Float], user_data: &mut U) -> Result<DVector<Float>, E> {
+        let key = key_of(x);
+        {
+            let mut state = self.state.borrow_mut();
+            if let Some(entry) = state.entries.get(&key) {
+                if let Some(gradient) = entry.gradient.clone() {
+                    state.hits += 1;
+                    state.touch(&key);
+                    return Ok(gradient);
+                }
+            }
+            state.misses += 1;
+        }
+        let gradient = self.inner.gradient(x, user_data)?;
+        let value = self.inner.evaluate(x, user_data)?;
+        self.state
+            .borrow_mut()
+            .insert_gradient(key, value, gradient.clone());
+        Ok(gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::convert::Infallible;
+
+    /// Counts how many times the wrapped function is actually called.
+    struct Counting {
+        calls: Cell<usize>,
+    }
+    impl CostFunction<(), Infallible> for Counting {
+        fn evaluate(&self, x: &[Float], _user_data: &mut ()) -> Result<Float, Infallible> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(x.iter().map(|v| v * v).sum())
+        }
+    }
+
+    #[test]
+    fn test_caches_repeated_points() {
+        let cached = Cached::new(Counting { calls: Cell::new(0) }, 8);
+        let x = [1.0, 2.0];
+        let a = cached.evaluate(&x, &mut ()).unwrap();
+        let b = cached.evaluate(&x, &mut ()).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(cached.inner().calls.get(), 1);
+        assert_eq!(cached.hits(), 1);
+        assert_eq!(cached.misses(), 1);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cached = Cached::new(Counting { calls: Cell::new(0) }, 2);
+        cached.evaluate(&[1.0], &mut ()).unwrap();
+        cached.evaluate(&[2.0], &mut ()).unwrap();
+        cached.evaluate(&[1.0], &mut ()).unwrap(); // hit, keeps 1.0 fresh
+        cached.evaluate(&[3.0], &mut ()).unwrap(); // evicts 2.0
+        cached.evaluate(&[2.0], &mut ()).unwrap(); // miss again
+        assert_eq!(cached.inner().calls.get(), 4);
+    }
+}
@@ -0,0 +1,163 @@
+//! Assorted diagnostic utilities.
+
+/// A memoizing cache adapter for expensive cost/gradient evaluations.
+pub mod cached;
+
+pub use cached::Cached;
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::traits::{central_difference, CostFunction, EPSILON_CBRT};
+use crate::Float;
+
+/// The result of comparing an analytic derivative against its central
+/// finite-difference approximation, component by component.
+#[derive(Debug, Clone)]
+pub struct DerivativeReport {
+    /// The absolute error $`|a_i - f_i|`$ of each component.
+    pub abs_errors: Vec<Float>,
+    /// The relative error $`|a_i - f_i| / \max(|a_i|, |f_i|, \epsilon)`$ of each
+    /// component.
+    pub rel_errors: Vec<Float>,
+    /// The largest relative error over all components.
+    pub max_rel_error: Float,
+    /// Whether every component's relative error is within the requested tolerance.
+    pub passed: bool,
+}
+
+impl DerivativeReport {
+    fn new(analytic: &[Float], finite: &[Float], rel_tol: Float) -> Self {
+        let abs_errors: Vec<Float> = analytic
+            .iter()
+            .zip(finite)
+            .map(|(a, f)| (a - f).abs())
+            .collect();
+        let rel_errors: Vec<Float> = analytic
+            .iter()
+            .zip(finite)
+            .map(|(a, f)| (a - f).abs() / a.abs().max(f.abs()).max(Float::EPSILON))
+            .collect();
+        let max_rel_error = rel_errors.iter().copied().fold(0.0, Float::max);
+        Self {
+            abs_errors,
+            rel_errors,
+            max_rel_error,
+            passed: max_rel_error <= rel_tol,
+        }
+    }
+
+    /// A one-line warning suitable for appending to [`Summary::message`], or `None`
+    /// if the check passed.
+    ///
+    /// [`Summary::message`]: crate::core::Summary::message
+    #[must_use]
+    pub fn warning(&self) -> Option<String> {
+        (!self.passed).then(|| {
+            format!(
+                "WARNING: analytic derivative disagrees with finite difference (max rel err {:.3e})",
+                self.max_rel_error
+            )
+        })
+    }
+}
+
+/// Internal central-difference gradient, computed via the shared
+/// [`central_difference`] helper so the check does not rely on a possibly-overridden
+/// [`CostFunction::gradient`].
+fn finite_gradient<U, E>(
+    function: &impl CostFunction<U, E>,
+    x: &[Float],
+    user_data: &mut U,
+) -> Result<DVector<Float>, E> {
+    central_difference(|xp, ud| function.evaluate(xp, ud), x, user_data)
+}
+
+/// Check a user-supplied analytic gradient against the central finite-difference
+/// approximation at `x`, returning per-component errors and a pass/fail against
+/// `rel_tol`.
+///
+/// This catches the single most common source of silent convergence failures: a
+/// buggy hand-written [`CostFunction::gradient`]. If the function does not override
+/// the default gradient, the two estimates agree trivially.
+///
+/// # Errors
+///
+/// Returns `E` if any underlying evaluation or gradient call fails.
+pub fn check_gradient<U, E>(
+    function: &impl CostFunction<U, E>,
+    x: &[Float],
+    user_data: &mut U,
+    rel_tol: Float,
+) -> Result<DerivativeReport, E> {
+    let analytic = function.gradient(x, user_data)?;
+    let finite = finite_gradient(function, x, user_data)?;
+    Ok(DerivativeReport::new(
+        analytic.as_slice(),
+        finite.as_slice(),
+        rel_tol,
+    ))
+}
+
+/// Check a user-supplied analytic Hessian against the central finite-difference of
+/// the gradient at `x`, returning per-component (column-major) errors and a pass/fail
+/// against `rel_tol`.
+///
+/// # Errors
+///
+/// Returns `E` if any underlying gradient evaluation fails.
+pub fn check_hessian<U, E>(
+    function: &impl CostFunction<U, E>,
+    analytic: &DMatrix<Float>,
+    x: &[Float],
+    user_data: &mut U,
+    rel_tol: Float,
+) -> Result<DerivativeReport, E> {
+    let n = x.len();
+    let mut finite = DMatrix::zeros(n, n);
+    let mut xp = x.to_vec();
+    for j in 0..n {
+        let h = EPSILON_CBRT * x[j].abs().max(1.0);
+        xp[j] = x[j] + h;
+        let gp = function.gradient(&xp, user_data)?;
+        xp[j] = x[j] - h;
+        let gm = function.gradient(&xp, user_data)?;
+        xp[j] = x[j];
+        for i in 0..n {
+            finite[(i, j)] = (gp[i] - gm[i]) / (2.0 * h);
+        }
+    }
+    Ok(DerivativeReport::new(
+        analytic.as_slice(),
+        finite.as_slice(),
+        rel_tol,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::DVector;
+    use std::convert::Infallible;
+
+    /// $`f(x) = \sum x_i^2`$, with a deliberately wrong gradient on the second
+    /// component to exercise the mismatch path.
+    struct BadGradient;
+    impl CostFunction<(), Infallible> for BadGradient {
+        fn evaluate(&self, x: &[Float], _user_data: &mut ()) -> Result<Float, Infallible> {
+            Ok(x.iter().map(|v| v * v).sum())
+        }
+        fn gradient(&self, x: &[Float], _user_data: &mut ()) -> Result<DVector<Float>, Infallible> {
+            // Correct would be 2*x; the second entry is wrong on purpose.
+            Ok(DVector::from_vec(vec![2.0 * x[0], 5.0 * x[1]]))
+        }
+    }
+
+    #[test]
+    fn test_detects_bad_gradient() {
+        let report = check_gradient(&BadGradient, &[1.0, 1.0], &mut (), 1e-4).unwrap();
+        assert!(!report.passed);
+        assert!(report.warning().is_some());
+        assert!(report.rel_errors[0] < 1e-4);
+        assert!(report.rel_errors[1] > 1e-4);
+    }
+}